@@ -2,12 +2,51 @@
 use bit_set::BitSet;
 #[cfg(feature = "hashbrown")]
 use hashbrown::HashSet as HashBrownSet;
+use std::borrow::Borrow;
 use std::collections::{BTreeSet, HashSet};
 use std::hash::{BuildHasher, Hash};
 #[cfg(test)]
 #[macro_use]
 extern crate quickcheck;
 
+/// Error returned by the fallible-allocation methods on [`Setlike`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SetlikeReserveError {
+    /// The computed capacity exceeded `isize::MAX` bytes.
+    CapacityOverflow,
+    /// The allocator returned an error.
+    AllocError,
+}
+
+impl std::fmt::Display for SetlikeReserveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetlikeReserveError::CapacityOverflow => write!(f, "capacity overflow"),
+            SetlikeReserveError::AllocError => write!(f, "memory allocation failed"),
+        }
+    }
+}
+
+impl std::error::Error for SetlikeReserveError {}
+
+impl From<std::collections::TryReserveError> for SetlikeReserveError {
+    fn from(_: std::collections::TryReserveError) -> Self {
+        // `std::collections::TryReserveError` doesn't expose its kind on stable, so we can't
+        // distinguish capacity overflow from an allocator failure here.
+        SetlikeReserveError::AllocError
+    }
+}
+
+#[cfg(feature = "hashbrown")]
+impl From<hashbrown::TryReserveError> for SetlikeReserveError {
+    fn from(e: hashbrown::TryReserveError) -> Self {
+        match e {
+            hashbrown::TryReserveError::CapacityOverflow => SetlikeReserveError::CapacityOverflow,
+            hashbrown::TryReserveError::AllocError { .. } => SetlikeReserveError::AllocError,
+        }
+    }
+}
+
 /// A Set-like object.
 pub trait Setlike<T: Sized> {
     /// `true` if the set contains `el`.
@@ -26,6 +65,28 @@ pub trait Setlike<T: Sized> {
     /// The number of elements in the set.
     fn len(&self) -> usize;
 
+    /// `true` if the set contains an element equivalent to the borrowed key `el`.
+    ///
+    /// Lets callers query with any borrowed form of `T` (e.g. a `&str` against a
+    /// `Setlike<String>`) without allocating an owned `T` just to call [`Setlike::contains`].
+    /// `Q` only needs `Hash + Eq`, matching the hash-based backends' own native borrowed-key
+    /// lookup, so this works for any such `T`/`Q` pair even when `Q` isn't `Ord`. `BTreeSet`
+    /// can't use its own `Ord`-based lookup under this bound and falls back to a linear scan;
+    /// see its impl for details.
+    fn contains_q<Q>(&self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    /// Remove the element equivalent to the borrowed key `el`, if present.
+    ///
+    /// Returns `true` if an element was removed. See [`Setlike::contains_q`] for why this takes
+    /// a borrowed key instead of `&T`.
+    fn remove_q<Q>(&mut self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
     /// Create an instance of the setlike with a hint that we will need room for `k` elements.
     ///
     /// Not all implementations support this operation; those that do not will simply create an
@@ -33,6 +94,196 @@ pub trait Setlike<T: Sized> {
     fn with_capacity(k: usize) -> Self
     where
         Self: Sized;
+
+    /// Reserve capacity for at least `additional` more elements, reporting an error instead of
+    /// aborting the process if the allocation can't be satisfied.
+    ///
+    /// The default implementation reserves nothing and always returns `Ok(())`; implementations
+    /// backed by an allocator that can report failure (the hash-based backends) override this
+    /// with a call to their own `try_reserve`.
+    fn try_reserve(&mut self, _additional: usize) -> Result<(), SetlikeReserveError> {
+        Ok(())
+    }
+
+    /// Fallibly create an instance of the setlike with room for `k` elements.
+    ///
+    /// The default implementation just calls [`Setlike::with_capacity`], which can abort the
+    /// process on allocation failure; implementations that can report the failure instead
+    /// override this.
+    fn try_with_capacity(k: usize) -> Result<Self, SetlikeReserveError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::with_capacity(k))
+    }
+
+    /// Insert every element of `iter` into the set.
+    fn extend_from<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for el in iter {
+            self.insert(el);
+        }
+    }
+
+    /// Build a new instance from an iterator, pre-sizing with the iterator's lower `size_hint`
+    /// bound so the hash-based backends don't rehash repeatedly as elements are inserted.
+    fn from_iter_sized<I: IntoIterator<Item = T>>(iter: I) -> Self
+    where
+        Self: Sized,
+    {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut set = Self::with_capacity(lower);
+        set.extend_from(iter);
+        set
+    }
+
+    /// Keep only the elements for which `keep` returns `true`, removing the rest.
+    ///
+    /// The default collects the elements to drop and removes them one at a time; implementations
+    /// with a native bulk `retain` (`HashSet`, `BTreeSet` and hashbrown's set) override this with
+    /// it. `BitSet` also overrides it, with its own collect-then-remove loop over `iter_copied`.
+    fn retain<F: FnMut(&T) -> bool>(&mut self, mut keep: F)
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        let to_remove: Vec<T> = self.iter().filter(|el| !keep(el)).cloned().collect();
+        for el in to_remove {
+            self.remove(&el);
+        }
+    }
+
+    /// The iterator type returned by [`Setlike::iter`].
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Iterate over the elements of the set by reference.
+    ///
+    /// Not every backend can provide a borrowing iterator: `BitSet`'s native iterator produces
+    /// `usize` values computed on the fly rather than references into storage, so its impl
+    /// yields an empty iterator here. Use [`Setlike::iter_copied`] for that case instead.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Iterate over the elements of the set by value.
+    ///
+    /// The default forwards to [`Setlike::iter`] and copies each element. Implementations whose
+    /// native iterator already yields owned values (currently `BitSet`) override this directly
+    /// instead of paying for a reference iterator that doesn't really exist.
+    fn iter_copied<'a>(&'a self) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + 'a,
+    {
+        self.iter().copied()
+    }
+
+    /// Insert every element of `other` into `self`.
+    ///
+    /// The default implementation iterates `other` and inserts each element into `self`;
+    /// implementations with a native bulk union (currently `BitSet`) override it with one that
+    /// works at the word level instead of probing element-by-element.
+    fn union_with(&mut self, other: &Self)
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        for el in other.iter() {
+            self.insert(el.clone());
+        }
+    }
+
+    /// Remove every element of `self` that is not also in `other`.
+    ///
+    /// See [`Setlike::union_with`] for the override rationale.
+    fn intersect_with(&mut self, other: &Self)
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        let to_remove: Vec<T> = self
+            .iter()
+            .filter(|el| !other.contains(el))
+            .cloned()
+            .collect();
+        for el in to_remove {
+            self.remove(&el);
+        }
+    }
+
+    /// Remove every element of `self` that is also in `other`.
+    ///
+    /// See [`Setlike::union_with`] for the override rationale.
+    fn difference_with(&mut self, other: &Self)
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        let to_remove: Vec<T> = self
+            .iter()
+            .filter(|el| other.contains(el))
+            .cloned()
+            .collect();
+        for el in to_remove {
+            self.remove(&el);
+        }
+    }
+
+    /// Leave `self` containing exactly the elements that are in `self` or `other`, but not both.
+    ///
+    /// See [`Setlike::union_with`] for the override rationale.
+    fn symmetric_difference_with(&mut self, other: &Self)
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        let to_remove: Vec<T> = self
+            .iter()
+            .filter(|el| other.contains(el))
+            .cloned()
+            .collect();
+        let to_add: Vec<T> = other
+            .iter()
+            .filter(|el| !self.contains(el))
+            .cloned()
+            .collect();
+        for el in to_remove {
+            self.remove(&el);
+        }
+        for el in to_add {
+            self.insert(el);
+        }
+    }
+
+    /// `true` if every element of `self` is also in `other`.
+    ///
+    /// See [`Setlike::union_with`] for the override rationale.
+    fn is_subset(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.iter().all(|el| other.contains(el))
+    }
+
+    /// `true` if every element of `other` is also in `self`.
+    ///
+    /// See [`Setlike::union_with`] for the override rationale.
+    fn is_superset(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        other.is_subset(self)
+    }
+
+    /// `true` if `self` and `other` share no elements.
+    ///
+    /// See [`Setlike::union_with`] for the override rationale.
+    fn is_disjoint(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.iter().all(|el| !other.contains(el))
+    }
 }
 
 impl<T: Sized + Eq + Hash, S: BuildHasher + Default> Setlike<T> for HashSet<T, S> {
@@ -55,6 +306,47 @@ impl<T: Sized + Eq + Hash, S: BuildHasher + Default> Setlike<T> for HashSet<T, S
     fn with_capacity(k: usize) -> Self {
         HashSet::with_capacity_and_hasher(k, S::default())
     }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), SetlikeReserveError> {
+        self.try_reserve(additional)
+            .map_err(SetlikeReserveError::from)
+    }
+
+    fn try_with_capacity(k: usize) -> Result<Self, SetlikeReserveError> {
+        let mut set = HashSet::with_hasher(S::default());
+        set.try_reserve(k).map_err(SetlikeReserveError::from)?;
+        Ok(set)
+    }
+
+    type Iter<'a>
+        = std::collections::hash_set::Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+
+    fn contains_q<Q>(&self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.contains(el)
+    }
+
+    fn remove_q<Q>(&mut self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove(el)
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, keep: F) {
+        self.retain(keep)
+    }
 }
 
 impl<T: Sized + Ord> Setlike<T> for BTreeSet<T> {
@@ -77,6 +369,49 @@ impl<T: Sized + Ord> Setlike<T> for BTreeSet<T> {
     fn with_capacity(_k: usize) -> Self {
         Self::new()
     }
+
+    fn try_reserve(&mut self, _additional: usize) -> Result<(), SetlikeReserveError> {
+        // `BTreeSet` has no notion of capacity, so there is nothing that can fail to allocate.
+        Ok(())
+    }
+
+    fn try_with_capacity(_k: usize) -> Result<Self, SetlikeReserveError> {
+        Ok(Self::new())
+    }
+
+    type Iter<'a>
+        = std::collections::btree_set::Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+
+    // `BTreeSet`'s own `Borrow`-based lookup needs `Q: Ord`, which `Setlike::contains_q` does
+    // not guarantee, so this falls back to a linear scan instead.
+    fn contains_q<Q>(&self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.iter().any(|x| x.borrow() == el)
+    }
+
+    fn remove_q<Q>(&mut self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let before = self.len();
+        self.retain(|x| x.borrow() != el);
+        self.len() != before
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, keep: F) {
+        self.retain(keep)
+    }
 }
 
 #[cfg(feature = "bit-set")]
@@ -100,6 +435,83 @@ impl Setlike<usize> for BitSet {
     fn with_capacity(k: usize) -> Self {
         BitSet::with_capacity(k)
     }
+
+    fn union_with(&mut self, other: &Self) {
+        self.union_with(other)
+    }
+
+    fn intersect_with(&mut self, other: &Self) {
+        self.intersect_with(other)
+    }
+
+    fn difference_with(&mut self, other: &Self) {
+        self.difference_with(other)
+    }
+
+    fn symmetric_difference_with(&mut self, other: &Self) {
+        self.symmetric_difference_with(other)
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        self.is_subset(other)
+    }
+
+    fn is_superset(&self, other: &Self) -> bool {
+        self.is_superset(other)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        self.is_disjoint(other)
+    }
+
+    // `bit_set::Iter` yields `usize` by value, not `&usize`, so there is no real borrowing
+    // iterator to hand out here. `iter_copied` below is the supported path; `iter` just yields
+    // nothing, mirroring how `with_capacity` degrades gracefully for unsupported operations.
+    type Iter<'a> = std::iter::Empty<&'a usize>;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        std::iter::empty()
+    }
+
+    fn iter_copied<'a>(&'a self) -> impl Iterator<Item = usize> + 'a
+    where
+        usize: Copy + 'a,
+    {
+        self.iter()
+    }
+
+    // `usize` is only ever `Borrow<usize>` (the reflexive blanket impl), so in practice `Q` here
+    // is always `usize` itself. There's no native borrowed-key lookup to forward to -- unlike the
+    // hash-based backends, a `BitSet` doesn't hold `T` values to hash or compare -- so this scans
+    // the set bits instead.
+    fn contains_q<Q>(&self, el: &Q) -> bool
+    where
+        usize: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.iter_copied().any(|x| Borrow::<Q>::borrow(&x) == el)
+    }
+
+    fn remove_q<Q>(&mut self, el: &Q) -> bool
+    where
+        usize: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let found = self.iter_copied().find(|x| Borrow::<Q>::borrow(x) == el);
+        match found {
+            Some(x) => self.remove(x),
+            None => false,
+        }
+    }
+
+    // `bit_set::BitSet` has no `retain` of its own, so this collects the indices to drop via
+    // `iter_copied` and removes them afterwards (mutating while iterating isn't an option here).
+    fn retain<F: FnMut(&usize) -> bool>(&mut self, mut keep: F) {
+        let to_remove: Vec<usize> = self.iter_copied().filter(|i| !keep(i)).collect();
+        for i in to_remove {
+            self.remove(i);
+        }
+    }
 }
 
 #[cfg(feature = "hashbrown")]
@@ -123,54 +535,252 @@ impl<T: Eq + Hash, S: BuildHasher + Default> Setlike<T> for HashBrownSet<T, S> {
     fn with_capacity(k: usize) -> Self {
         HashBrownSet::with_capacity_and_hasher(k, S::default())
     }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), SetlikeReserveError> {
+        self.try_reserve(additional)
+            .map_err(SetlikeReserveError::from)
+    }
+
+    fn try_with_capacity(k: usize) -> Result<Self, SetlikeReserveError> {
+        let mut set = HashBrownSet::with_hasher(S::default());
+        set.try_reserve(k).map_err(SetlikeReserveError::from)?;
+        Ok(set)
+    }
+
+    type Iter<'a>
+        = hashbrown::hash_set::Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+
+    fn contains_q<Q>(&self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.contains(el)
+    }
+
+    fn remove_q<Q>(&mut self, el: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove(el)
+    }
+
+    fn retain<F: FnMut(&T) -> bool>(&mut self, keep: F) {
+        self.retain(keep)
+    }
 }
 
 #[cfg(test)]
 mod test {
 
+    // `Setlike` has a GAT (`Iter`) and a method returning `impl Trait`, so it isn't dyn
+    // compatible -- these helpers are generic over `S: Setlike<$e>` instead of going through
+    // `&mut Setlike<$e>` as a trait object.
     macro_rules! set_test {
         ($id:ident, $t:ty, $e:ty, $($setup:item),*) => {
             mod $id {
                 use super::super::*;
+
+                fn check_contains_after_insert<S: Setlike<$e>>(s: &mut S, u: $e) -> bool {
+                    s.insert(u);
+                    s.contains(&u)
+                }
+
+                fn check_not_contains_after_remove<S: Setlike<$e>>(s: &mut S, u: $e) -> bool {
+                    s.insert(u);
+                    let contained = s.contains(&u);
+                    s.remove(&u);
+                    contained && !s.contains(&u)
+                }
+
+                fn check_insert_twice<S: Setlike<$e>>(s: &mut S, u: $e) -> bool {
+                    s.insert(u);
+                    !s.insert(u)
+                }
+
+                fn check_remove_twice<S: Setlike<$e>>(s: &mut S, u: $e) -> bool {
+                    s.insert(u);
+                    s.remove(&u) && !s.remove(&u)
+                }
+
+                fn check_len_increments<S: Setlike<$e>>(s: &mut S, u: $e) -> bool {
+                    let l = s.len();
+                    // either u is already in s, or s has its length increased
+                    !s.insert(u) || s.len() == l + 1
+                }
+
+                fn check_union_with_is_superset<S: Setlike<$e> + Clone>(a: S, b: S) -> bool {
+                    let mut u = a.clone();
+                    u.union_with(&b);
+                    u.is_superset(&a) && u.is_superset(&b)
+                }
+
+                fn check_intersect_with_keeps_common<S: Setlike<$e> + Clone>(a: S, b: S) -> bool {
+                    let mut i = a.clone();
+                    i.intersect_with(&b);
+                    i.iter_copied().all(|x| a.contains(&x) && b.contains(&x))
+                        && a.iter_copied().filter(|x| b.contains(x)).count() == i.len()
+                }
+
+                fn check_difference_with_removes_other<S: Setlike<$e> + Clone>(a: S, b: S) -> bool {
+                    let mut d = a.clone();
+                    d.difference_with(&b);
+                    d.iter_copied().all(|x| a.contains(&x) && !b.contains(&x))
+                }
+
+                fn check_symmetric_difference_with_is_xor<S: Setlike<$e> + Clone>(
+                    a: S,
+                    b: S,
+                ) -> bool {
+                    let mut s = a.clone();
+                    s.symmetric_difference_with(&b);
+                    s.iter_copied().all(|x| a.contains(&x) != b.contains(&x))
+                        && a.iter_copied().filter(|x| !b.contains(x)).count()
+                            + b.iter_copied().filter(|x| !a.contains(x)).count()
+                            == s.len()
+                }
+
+                fn check_is_subset_is_reflexive<S: Setlike<$e>>(a: S) -> bool {
+                    a.is_subset(&a)
+                }
+
+                fn check_difference_with_is_disjoint<S: Setlike<$e> + Clone>(a: S, b: S) -> bool {
+                    let mut d = a.clone();
+                    d.difference_with(&b);
+                    d.is_disjoint(&b)
+                }
+
+                fn check_contains_q_after_insert<S: Setlike<$e>>(s: &mut S, u: $e) -> bool {
+                    s.insert(u);
+                    s.contains_q(&u)
+                }
+
+                fn check_not_contains_q_after_remove_q<S: Setlike<$e>>(s: &mut S, u: $e) -> bool {
+                    s.insert(u);
+                    let contained = s.contains_q(&u);
+                    s.remove_q(&u);
+                    contained && !s.contains_q(&u)
+                }
+
+                fn check_retain_keeps_only_even<S: Setlike<$e> + Clone>(a: S) -> bool {
+                    let mut r = a.clone();
+                    r.retain(|x| x % 2 == 0);
+                    r.iter_copied().all(|x| x % 2 == 0)
+                        && a.iter_copied().filter(|x| x % 2 == 0).count() == r.len()
+                }
+
+                fn check_try_with_capacity_is_empty<S: Setlike<$e>>(k: usize) -> bool {
+                    // cap k so this can't try to allocate an absurd amount of memory
+                    let k = k % 1024;
+                    S::try_with_capacity(k)
+                        .map(|s| s.len() == 0)
+                        .unwrap_or(false)
+                }
+
+                fn check_try_reserve_ok<S: Setlike<$e>>(s: &mut S, k: usize) -> bool {
+                    s.try_reserve(k % 1024).is_ok()
+                }
+
+                fn check_from_iter_sized_roundtrips<S: Setlike<$e>>(elems: Vec<$e>) -> bool {
+                    let set = S::from_iter_sized(elems.iter().copied());
+                    elems.iter().all(|e| set.contains(e))
+                }
+
+                fn check_extend_from_adds_all<S: Setlike<$e>>(s: &mut S, elems: Vec<$e>) -> bool {
+                    s.extend_from(elems.iter().copied());
+                    elems.iter().all(|e| s.contains(e))
+                }
+
                 $($setup)+
 
                 quickcheck! {
                     fn contains_after_insert(set: $t, u: $e) -> bool {
                         let mut set = set;
-                        let s: &mut Setlike<$e> = &mut set;
-                        s.insert(u);
-                        s.contains(&u)
+                        check_contains_after_insert(&mut set, u)
                     }
 
                     fn not_contains_after_remove(set: $t, u: $e) -> bool {
                         let mut set = set;
-                        let s: &mut Setlike<$e> = &mut set;
-                        s.insert(u);
-                        let contained = s.contains(&u);
-                        s.remove(&u);
-                        contained && ! s.contains(&u)
+                        check_not_contains_after_remove(&mut set, u)
                     }
 
                     fn insert_twice(set: $t, u: $e) -> bool {
                         let mut set = set;
-                        let s: &mut Setlike<$e> = &mut set;
-                        s.insert(u);
-                        !s.insert(u)
+                        check_insert_twice(&mut set, u)
                     }
 
                     fn remove_twice(set: $t, u: $e) -> bool {
                         let mut set = set;
-                        let s: &mut Setlike<$e> = &mut set;
-                        s.insert(u);
-                        s.remove(&u) && !s.remove(&u)
+                        check_remove_twice(&mut set, u)
                     }
 
                     fn len_increments(set: $t, u: $e) -> bool {
                         let mut set = set;
-                        let s: &mut Setlike<$e> = &mut set;
-                        let l = s.len();
-                        // either u is already in s, or s has its length increased
-                        !s.insert(u) || s.len() == l + 1
+                        check_len_increments(&mut set, u)
+                    }
+
+                    fn union_with_is_superset(a: $t, b: $t) -> bool {
+                        check_union_with_is_superset(a, b)
+                    }
+
+                    fn intersect_with_keeps_common(a: $t, b: $t) -> bool {
+                        check_intersect_with_keeps_common(a, b)
+                    }
+
+                    fn difference_with_removes_other(a: $t, b: $t) -> bool {
+                        check_difference_with_removes_other(a, b)
+                    }
+
+                    fn symmetric_difference_with_is_xor(a: $t, b: $t) -> bool {
+                        check_symmetric_difference_with_is_xor(a, b)
+                    }
+
+                    fn is_subset_is_reflexive(a: $t) -> bool {
+                        check_is_subset_is_reflexive(a)
+                    }
+
+                    fn difference_with_is_disjoint(a: $t, b: $t) -> bool {
+                        check_difference_with_is_disjoint(a, b)
+                    }
+
+                    fn contains_q_after_insert(set: $t, u: $e) -> bool {
+                        let mut set = set;
+                        check_contains_q_after_insert(&mut set, u)
+                    }
+
+                    fn not_contains_q_after_remove_q(set: $t, u: $e) -> bool {
+                        let mut set = set;
+                        check_not_contains_q_after_remove_q(&mut set, u)
+                    }
+
+                    fn retain_keeps_only_even(a: $t) -> bool {
+                        check_retain_keeps_only_even(a)
+                    }
+
+                    fn try_with_capacity_is_empty(k: usize) -> bool {
+                        check_try_with_capacity_is_empty::<$t>(k)
+                    }
+
+                    fn try_reserve_ok(set: $t, k: usize) -> bool {
+                        let mut set = set;
+                        check_try_reserve_ok(&mut set, k)
+                    }
+
+                    fn from_iter_sized_roundtrips(elems: Vec<$e>) -> bool {
+                        check_from_iter_sized_roundtrips::<$t>(elems)
+                    }
+
+                    fn extend_from_adds_all(set: $t, elems: Vec<$e>) -> bool {
+                        let mut set = set;
+                        check_extend_from_adds_all(&mut set, elems)
                     }
                 }
             }